@@ -0,0 +1,26 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+// COMPOSITION COEFFICIENTS
+// ================================================================================================
+/// Holds the random coefficients, drawn from the public coin, used to build random linear
+/// combinations when composing trace, auxiliary trace, and constraint composition polynomials
+/// into the DEEP composition polynomial.
+#[derive(Debug, Clone)]
+pub struct CompositionCoefficients<E> {
+    /// One entry per main trace column: (coefficient for T'_i, coefficient for T''_i,
+    /// coefficients for each of the nontrivial Frobenius-conjugate terms T^(k)_i, one per
+    /// nontrivial conjugate of the out-of-domain point z — empty when the proof is generated
+    /// over the base field).
+    pub trace: Vec<(E, E, Vec<E>)>,
+    /// One entry per auxiliary (RAP) trace column, across all auxiliary segments, in order:
+    /// (coefficient for T'_i, coefficient for T''_i). There is no conjugate term for auxiliary
+    /// columns, since they are not required to be defined over the base field.
+    pub aux_trace: Vec<(E, E)>,
+    /// One entry per constraint composition column.
+    pub constraints: Vec<E>,
+    /// Coefficients (cc_0, cc_1) used for the final degree adjustment.
+    pub degree: (E, E),
+}
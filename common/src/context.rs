@@ -0,0 +1,28 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use crate::ProverOptions;
+
+// COMPUTATION CONTEXT
+// ================================================================================================
+/// Holds the parameters of a computation that are shared between the prover and the verifier,
+/// but are not part of the AIR itself (e.g. proof generation options).
+// TODO: change from context to AIR
+#[derive(Debug, Clone)]
+pub struct ComputationContext {
+    options: ProverOptions,
+}
+
+impl ComputationContext {
+    /// Creates a new [ComputationContext] from the given proof generation options.
+    pub fn new(options: ProverOptions) -> Self {
+        ComputationContext { options }
+    }
+
+    /// Returns the proof generation options associated with this context.
+    pub fn options(&self) -> &ProverOptions {
+        &self.options
+    }
+}
@@ -0,0 +1,21 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+// EVALUATION FRAME
+// ================================================================================================
+/// Holds the out-of-domain evaluations of the trace registers at the two points (z and z * g)
+/// needed to verify the transition constraints, for both the main trace segment and any
+/// auxiliary (RAP) trace segments.
+///
+/// `aux_current`/`aux_next` are empty when the computation has no auxiliary trace segments;
+/// otherwise they hold one entry per auxiliary column, in the same order as the columns appear
+/// across all auxiliary segments.
+#[derive(Debug, Clone)]
+pub struct EvaluationFrame<E> {
+    pub current: Vec<E>,
+    pub next: Vec<E>,
+    pub aux_current: Vec<E>,
+    pub aux_next: Vec<E>,
+}
@@ -0,0 +1,14 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+mod composition_coefficients;
+mod context;
+mod evaluation_frame;
+mod options;
+
+pub use composition_coefficients::CompositionCoefficients;
+pub use context::ComputationContext;
+pub use evaluation_frame::EvaluationFrame;
+pub use options::{FieldExtension, ProverOptions};
@@ -0,0 +1,62 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+// FIELD EXTENSION
+// ================================================================================================
+/// Defines the field extension (if any) to be used during proof generation and verification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldExtension {
+    None,
+    Quadratic,
+    Cubic,
+}
+
+impl FieldExtension {
+    /// Returns true if this extension is [FieldExtension::None].
+    pub fn is_none(&self) -> bool {
+        matches!(self, Self::None)
+    }
+
+    /// Returns the degree of this extension over the base field (1 for [FieldExtension::None]).
+    pub fn degree(&self) -> usize {
+        match self {
+            Self::None => 1,
+            Self::Quadratic => 2,
+            Self::Cubic => 3,
+        }
+    }
+}
+
+// PROVER OPTIONS
+// ================================================================================================
+/// Defines parameters for proof generation that affect proof soundness and size, but have no
+/// bearing on the computation itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProverOptions {
+    field_extension: FieldExtension,
+    use_evaluation_domain_composition: bool,
+}
+
+impl ProverOptions {
+    /// Creates a new instance of [ProverOptions] with the given parameters.
+    pub fn new(field_extension: FieldExtension, use_evaluation_domain_composition: bool) -> Self {
+        ProverOptions {
+            field_extension,
+            use_evaluation_domain_composition,
+        }
+    }
+
+    /// Returns the field extension used during proof generation and verification.
+    pub fn field_extension(&self) -> FieldExtension {
+        self.field_extension
+    }
+
+    /// Returns true if the DEEP composition polynomial should be accumulated directly in
+    /// evaluation form over the LDE domain, rather than in coefficient form. This trades memory
+    /// for avoiding the final coefficient-to-evaluation FFT pass.
+    pub fn use_evaluation_domain_composition(&self) -> bool {
+        self.use_evaluation_domain_composition
+    }
+}
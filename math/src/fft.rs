@@ -0,0 +1,33 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use crate::{
+    field::{FieldElement, StarkField},
+    polynom,
+};
+
+/// Evaluates `poly` (in coefficient form) over the domain `{offset * t : t in twiddles}`, where
+/// `twiddles` holds every point of the unit-offset low-degree-extension domain (so
+/// `twiddles.len() == poly.len() * blowup_factor`).
+pub fn evaluate_poly_with_offset<B, E>(
+    poly: &[E],
+    twiddles: &[B],
+    offset: B,
+    blowup_factor: usize,
+) -> Vec<E>
+where
+    B: StarkField,
+    E: FieldElement + From<B>,
+{
+    assert_eq!(
+        twiddles.len(),
+        poly.len() * blowup_factor,
+        "number of twiddles must match poly length times the blowup factor"
+    );
+    twiddles
+        .iter()
+        .map(|&t| polynom::eval(poly, E::from(offset * t)))
+        .collect()
+}
@@ -0,0 +1,60 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use std::fmt::Debug;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+pub mod toy;
+
+// FIELD ELEMENT
+// ================================================================================================
+/// Defines an element in a finite field.
+pub trait FieldElement:
+    Copy
+    + Clone
+    + Debug
+    + Send
+    + Sync
+    + PartialEq
+    + Eq
+    + Add<Output = Self>
+    + AddAssign
+    + Sub<Output = Self>
+    + SubAssign
+    + Mul<Output = Self>
+    + MulAssign
+    + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// Returns the multiplicative inverse of this field element.
+    fn inv(self) -> Self;
+
+    /// Exponentiates this field element by the provided power.
+    fn exp(self, power: u128) -> Self;
+
+    /// Returns a vector of `n` zero elements.
+    fn zeroed_vector(n: usize) -> Vec<Self> {
+        vec![Self::ZERO; n]
+    }
+
+    /// Returns the result of applying the Frobenius endomorphism (the base-field q-power map)
+    /// to this element once. For a degree `d` extension field, applying this `d` times returns
+    /// the original element; applying it `d - 1` times in sequence produces the `d - 1`
+    /// nontrivial Galois conjugates of the element.
+    ///
+    /// For elements of the base field itself, this is the identity map.
+    fn frobenius(&self) -> Self;
+}
+
+// STARK FIELD
+// ================================================================================================
+/// Defines a finite field suitable for FRI-based STARKs: in addition to being a [FieldElement],
+/// it must support computing roots of unity of any power-of-two order up to its two-adicity.
+pub trait StarkField: FieldElement {
+    /// Returns a generator of the subgroup of order `2^n` in this field.
+    fn get_root_of_unity(n: u32) -> Self;
+}
@@ -0,0 +1,202 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! A small concrete field and its quadratic extension, used to exercise generic STARK code
+//! (in the `prover` crate) in tests. The modulus is far too small to be sound for an
+//! actual proof system; this module exists purely so that generic code written against
+//! [FieldElement]/[StarkField] has a concrete type to run against in tests.
+
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use super::{FieldElement, StarkField};
+
+/// Elements of the prime field Z/97Z. 96 = 2^5 * 3, so this field has 2-adicity 5.
+const MODULUS: u64 = 97;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BaseElement(u64);
+
+impl BaseElement {
+    pub fn new(value: u64) -> Self {
+        BaseElement(value % MODULUS)
+    }
+
+    fn pow(self, mut power: u64) -> Self {
+        let mut result = BaseElement::ONE;
+        let mut base = self;
+        while power > 0 {
+            if power & 1 == 1 {
+                result *= base;
+            }
+            base *= base;
+            power >>= 1;
+        }
+        result
+    }
+}
+
+impl Add for BaseElement {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        BaseElement((self.0 + rhs.0) % MODULUS)
+    }
+}
+impl AddAssign for BaseElement {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+impl Sub for BaseElement {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        BaseElement((self.0 + MODULUS - rhs.0) % MODULUS)
+    }
+}
+impl SubAssign for BaseElement {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+impl Mul for BaseElement {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        BaseElement((self.0 * rhs.0) % MODULUS)
+    }
+}
+impl MulAssign for BaseElement {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+impl Neg for BaseElement {
+    type Output = Self;
+    fn neg(self) -> Self {
+        BaseElement((MODULUS - self.0) % MODULUS)
+    }
+}
+
+impl FieldElement for BaseElement {
+    const ZERO: Self = BaseElement(0);
+    const ONE: Self = BaseElement(1);
+
+    fn inv(self) -> Self {
+        assert_ne!(self, Self::ZERO, "cannot invert zero");
+        self.pow(MODULUS - 2)
+    }
+
+    fn exp(self, power: u128) -> Self {
+        self.pow((power % (MODULUS - 1) as u128) as u64)
+    }
+
+    // this is the base field, so Frobenius is the identity map
+    fn frobenius(&self) -> Self {
+        *self
+    }
+}
+
+impl StarkField for BaseElement {
+    fn get_root_of_unity(n: u32) -> Self {
+        assert!(n <= 5, "this field only has 2-adicity 5");
+        // 28 is a generator of the order-32 subgroup of Z/97Z
+        let h = BaseElement::new(28);
+        h.pow(1 << (5 - n))
+    }
+}
+
+/// Elements of the quadratic extension field `Z/97Z[u] / (u^2 - 5)` (5 is a quadratic
+/// nonresidue mod 97), represented as `a + b*u`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuadElement(BaseElement, BaseElement);
+
+impl QuadElement {
+    pub fn new(a: BaseElement, b: BaseElement) -> Self {
+        QuadElement(a, b)
+    }
+
+    const NON_RESIDUE: BaseElement = BaseElement(5);
+}
+
+impl Add for QuadElement {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        QuadElement(self.0 + rhs.0, self.1 + rhs.1)
+    }
+}
+impl AddAssign for QuadElement {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+impl Sub for QuadElement {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        QuadElement(self.0 - rhs.0, self.1 - rhs.1)
+    }
+}
+impl SubAssign for QuadElement {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+impl Mul for QuadElement {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        // (a1 + b1*u) * (a2 + b2*u) = (a1*a2 + NON_RESIDUE*b1*b2) + (a1*b2 + a2*b1)*u
+        let a = self.0 * rhs.0 + Self::NON_RESIDUE * self.1 * rhs.1;
+        let b = self.0 * rhs.1 + rhs.0 * self.1;
+        QuadElement(a, b)
+    }
+}
+impl MulAssign for QuadElement {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+impl Neg for QuadElement {
+    type Output = Self;
+    fn neg(self) -> Self {
+        QuadElement(-self.0, -self.1)
+    }
+}
+
+impl From<BaseElement> for QuadElement {
+    fn from(value: BaseElement) -> Self {
+        QuadElement(value, BaseElement::ZERO)
+    }
+}
+
+impl FieldElement for QuadElement {
+    const ZERO: Self = QuadElement(BaseElement::ZERO, BaseElement::ZERO);
+    const ONE: Self = QuadElement(BaseElement::ONE, BaseElement::ZERO);
+
+    fn inv(self) -> Self {
+        assert_ne!(self, Self::ZERO, "cannot invert zero");
+        // norm(a + b*u) = (a + b*u)(a - b*u) = a^2 - NON_RESIDUE*b^2, which lies in the base
+        // field; (a + b*u)^-1 = (a - b*u) / norm
+        let norm = self.0 * self.0 - Self::NON_RESIDUE * self.1 * self.1;
+        let norm_inv = norm.inv();
+        QuadElement(self.0 * norm_inv, -self.1 * norm_inv)
+    }
+
+    fn exp(self, power: u128) -> Self {
+        let mut result = Self::ONE;
+        let mut base = self;
+        let mut power = power;
+        while power > 0 {
+            if power & 1 == 1 {
+                result *= base;
+            }
+            base *= base;
+            power >>= 1;
+        }
+        result
+    }
+
+    // Frobenius (the p-power map) on GF(p^2) sends a + b*u to a - b*u, since u^p = -u (u is a
+    // quadratic nonresidue, so u^((p - 1) / 2) = -1, and u^p = u * (u^2)^((p - 1) / 2) = -u).
+    fn frobenius(&self) -> Self {
+        QuadElement(self.0, -self.1)
+    }
+}
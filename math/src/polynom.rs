@@ -0,0 +1,50 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use crate::field::FieldElement;
+
+/// Evaluates a polynomial, given by its coefficients (low-to-high degree), at `x` via Horner's
+/// method.
+pub fn eval<E: FieldElement>(poly: &[E], x: E) -> E {
+    let mut result = E::ZERO;
+    for &coeff in poly.iter().rev() {
+        result = result * x + coeff;
+    }
+    result
+}
+
+/// Returns the degree of `poly`, i.e. the index of its highest nonzero coefficient (0 for the
+/// zero polynomial).
+pub fn degree_of<E: FieldElement>(poly: &[E]) -> usize {
+    for i in (0..poly.len()).rev() {
+        if poly[i] != E::ZERO {
+            return i;
+        }
+    }
+    0
+}
+
+/// Divides `poly` in place by `(x - divisor_root)`, assuming `divisor_root` is an exact root of
+/// `poly` (the remainder is discarded rather than checked). Only division by a degree-1 divisor
+/// is supported, since that is the only case needed by the DEEP composition logic that calls
+/// into this function.
+pub fn syn_div_in_place<E: FieldElement>(poly: &mut [E], degree: usize, divisor_root: E) {
+    assert_eq!(degree, 1, "only division by a degree-1 divisor is supported");
+    assert!(!poly.is_empty());
+
+    let n = poly.len();
+    // synthetic division by (x - divisor_root), processing coefficients from the top down
+    for i in (1..n).rev() {
+        let carry = poly[i] * divisor_root;
+        poly[i - 1] += carry;
+    }
+
+    // the quotient (one degree lower than the dividend) now lives in poly[1..n]; shift it down
+    // into poly[0..n - 1] and zero out the vacated top coefficient
+    for i in 0..n - 1 {
+        poly[i] = poly[i + 1];
+    }
+    poly[n - 1] = E::ZERO;
+}
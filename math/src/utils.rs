@@ -0,0 +1,58 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use crate::field::FieldElement;
+
+/// Returns the base-2 logarithm of `n`, which must be a power of two.
+pub fn log2(n: usize) -> u32 {
+    assert!(n.is_power_of_two(), "n must be a power of two, but was {}", n);
+    n.trailing_zeros()
+}
+
+/// Inverts all provided elements in place using batched (Montgomery) inversion: a single
+/// field inversion is used to invert the whole slice, rather than one inversion per element.
+/// Elements are assumed to be nonzero.
+pub fn batch_inversion<E: FieldElement>(values: &mut [E]) {
+    if values.is_empty() {
+        return;
+    }
+
+    // compute running products: products[i] = values[0] * values[1] * ... * values[i]
+    let mut products = Vec::with_capacity(values.len());
+    let mut acc = E::ONE;
+    for &value in values.iter() {
+        acc *= value;
+        products.push(acc);
+    }
+
+    // invert the product of all elements with a single inversion
+    let mut inv = acc.inv();
+
+    // walk back through the products, peeling off one inverted element at a time
+    for i in (1..values.len()).rev() {
+        let value = values[i];
+        values[i] = products[i - 1] * inv;
+        inv *= value;
+    }
+    values[0] = inv;
+}
+
+/// Computes `acc[i] += E::from(poly[i]) * k` for all `i`.
+pub fn mul_acc<E, B>(acc: &mut [E], poly: &[B], k: E)
+where
+    B: Copy,
+    E: FieldElement + From<B>,
+{
+    for (a, &p) in acc.iter_mut().zip(poly.iter()) {
+        *a += E::from(p) * k;
+    }
+}
+
+/// Computes `acc[i] += poly[i]` for all `i`.
+pub fn add_in_place<E: FieldElement>(acc: &mut [E], poly: &[E]) {
+    for (a, &p) in acc.iter_mut().zip(poly.iter()) {
+        *a += p;
+    }
+}
@@ -0,0 +1,65 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use math::{field::StarkField, utils};
+
+// STARK DOMAIN
+// ================================================================================================
+/// Holds the trace-domain twiddles and the low-degree-extension (LDE) domain used throughout
+/// proof generation. The LDE domain is the trace domain's root-of-unity subgroup, blown up by
+/// `blowup_factor` and shifted by `offset` (a coset of the larger subgroup).
+pub struct StarkDomain<B: StarkField> {
+    twiddles: Vec<B>,
+    lde_domain: Vec<B>,
+    offset: B,
+    blowup_factor: usize,
+}
+
+impl<B: StarkField> StarkDomain<B> {
+    /// Creates a new [StarkDomain] for a trace of the given length, extended by `blowup_factor`
+    /// and shifted by `offset`.
+    pub fn new(trace_length: usize, blowup_factor: usize, offset: B) -> Self {
+        assert!(trace_length.is_power_of_two(), "trace_length must be a power of two");
+        assert!(blowup_factor.is_power_of_two(), "blowup_factor must be a power of two");
+
+        let lde_domain_size = trace_length * blowup_factor;
+        let g = B::get_root_of_unity(utils::log2(lde_domain_size));
+
+        let mut twiddles = Vec::with_capacity(lde_domain_size);
+        let mut x = B::ONE;
+        for _ in 0..lde_domain_size {
+            twiddles.push(x);
+            x *= g;
+        }
+        let lde_domain = twiddles.iter().map(|&t| offset * t).collect();
+
+        StarkDomain {
+            twiddles,
+            lde_domain,
+            offset,
+            blowup_factor,
+        }
+    }
+
+    /// Returns the unit (un-shifted) root-of-unity domain of size `trace_length * blowup_factor`.
+    pub fn trace_twiddles(&self) -> &[B] {
+        &self.twiddles
+    }
+
+    /// Returns the coset offset the LDE domain is shifted by.
+    pub fn offset(&self) -> B {
+        self.offset
+    }
+
+    /// Returns the factor by which the trace domain is blown up to produce the LDE domain.
+    pub fn trace_to_lde_blowup(&self) -> usize {
+        self.blowup_factor
+    }
+
+    /// Returns the LDE domain: `offset * trace_twiddles()`.
+    pub fn lde_values(&self) -> &[B] {
+        &self.lde_domain
+    }
+}
@@ -0,0 +1,12 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+mod domain;
+mod trace_table;
+
+pub use domain::StarkDomain;
+pub use trace_table::TracePolyTable;
+
+pub mod monolith;
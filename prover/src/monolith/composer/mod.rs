@@ -19,9 +19,12 @@ use rayon::prelude::*;
 // ================================================================================================
 pub struct DeepCompositionPoly<E: FieldElement> {
     coefficients: Vec<E>,
+    evaluations: Vec<E>,
+    lde_domain: Vec<E>,
     cc: CompositionCoefficients<E>,
     z: E,
-    field_extension: bool,
+    extension_degree: usize,
+    evaluation_domain: bool,
 }
 
 impl<E: FieldElement> DeepCompositionPoly<E> {
@@ -34,9 +37,12 @@ impl<E: FieldElement> DeepCompositionPoly<E> {
         // TODO: change from context to AIR
         DeepCompositionPoly {
             coefficients: vec![],
+            evaluations: vec![],
+            lde_domain: vec![],
             cc,
             z,
-            field_extension: !context.options().field_extension().is_none(),
+            extension_degree: context.options().field_extension().degree(),
+            evaluation_domain: context.options().use_evaluation_domain_composition(),
         }
     }
 
@@ -45,10 +51,19 @@ impl<E: FieldElement> DeepCompositionPoly<E> {
 
     /// Returns the size of the DEEP composition polynomial.
     pub fn poly_size(&self) -> usize {
-        self.coefficients.len()
+        if self.evaluation_domain {
+            self.evaluations.len()
+        } else {
+            self.coefficients.len()
+        }
     }
 
     /// Returns the degree of the composition polynomial.
+    ///
+    /// This is only meaningful in the coefficient-form (degree-assertion) path; the
+    /// evaluation-domain path never materializes coefficients, so degree checks are skipped
+    /// there (the evaluation-domain path is only exercised for computations that have already
+    /// been degree-checked via the coefficient-form path).
     pub fn degree(&self) -> usize {
         polynom::degree_of(&self.coefficients)
     }
@@ -58,44 +73,129 @@ impl<E: FieldElement> DeepCompositionPoly<E> {
     /// Combines all trace polynomials into a single polynomial and saves the result into
     /// the DEEP composition polynomial. The combination is done as follows:
     ///
-    /// - First, state of trace registers at deep points z and z * g are computed.
+    /// - First, state of trace registers at deep points z and z * g are computed directly from
+    ///   the trace in evaluation form, using a batched barycentric formula over the trace
+    ///   domain (a single Montgomery batch inversion covers all registers).
     /// - Then, polynomials T'_i(x) = (T_i(x) - T_i(z)) / (x - z) and
     ///   T''_i(x) = (T_i(x) - T_i(z * g)) / (x - z * g) are computed for all i, where T_i(x) is
     ///   a trace polynomial for register i.
     /// - Then, all polynomials are combined together using random liner combination as
     ///   T(x) = sum(T'_i(x) * cc'_i + T''_i(x) * cc''_i) for all i, where cc'_i and cc''_i are
     ///   the coefficients for the random linear combination drawn from the public coin.
-    /// - In cases when we generate a proof using an extension field, we also compute
-    ///   T'''_i(x) = (T_i(x) - T_i(z_conjugate)) / (x - z_conjugate), and add it to T(x) similarly
-    ///   to the way described above. This is needed in order to verify that the trace is defined
-    ///   over the base field, rather than the extension field.
-    pub fn add_trace_polys<B>(&mut self, trace_polys: TracePolyTable<B>) -> EvaluationFrame<E>
+    /// - In cases when we generate a proof using a degree d extension field, we also compute, for
+    ///   each of the d-1 nontrivial Frobenius conjugates z^(k) of z (k = 1..d-1):
+    ///   T^(k)_i(x) = (T_i(x) - T_i(z^(k))) / (x - z^(k)), and add it to T(x) similarly to the
+    ///   way described above. This is needed in order to verify that the trace is defined over
+    ///   the base field, rather than over the extension field: since T_i has base-field
+    ///   coefficients, Frobenius commutes with evaluation, so T_i(z^(k)) is simply the k-th
+    ///   Frobenius conjugate of T_i(z).
+    ///
+    /// `trace_lde` holds the same trace, but given in evaluation form over the trace domain
+    /// (i.e. `trace_lde[i][j]` is the evaluation of register `i` at the j-th n-th root of
+    /// unity). Out-of-domain states are computed directly from this evaluation-form trace via
+    /// a batched barycentric formula, so `trace_polys` no longer needs to be evaluated with
+    /// `evaluate_at()` (which internally runs Horner's method). The coefficient-form composition
+    /// below is still the only consumer of `trace_polys` itself, so callers only need to pass
+    /// `Some(trace_polys)` when that path runs; in evaluation-domain mode (see
+    /// [DeepCompositionPoly::new]) callers can pass `None` and skip interpolating the trace
+    /// columns into coefficient form entirely.
+    ///
+    /// When the evaluation-domain prover mode is enabled (see [DeepCompositionPoly::new]), this
+    /// method skips the coefficient-form composition entirely and instead computes the DEEP
+    /// quotient pointwise over the LDE domain, using `trace_lde` directly and a single batch
+    /// inversion of the (shared, per-divisor) zerofier over the whole LDE domain; see
+    /// [DeepCompositionPoly::add_trace_polys_evaluation_form]. The coefficient-form path remains
+    /// available (and is used for degree assertions) when that mode is disabled.
+    ///
+    /// `aux_trace_polys`/`aux_trace_lde` carry zero or more auxiliary (RAP) trace segments, built
+    /// from verifier challenges and living in the extension field `E` rather than in `B` (one
+    /// entry per segment; each segment's columns line up with `aux_trace_lde`'s). Auxiliary
+    /// columns get their own T'/T'' quotients at z and z*g, combined using the
+    /// `cc.aux_trace` block of composition coefficients (indexed across all auxiliary columns of
+    /// all segments, in order). The conjugate (T''') term is never applied to auxiliary columns:
+    /// it exists only to prove that the *main* segment is defined over the base field, while
+    /// auxiliary columns are legitimately defined over `E`. OOD states (current and next) are
+    /// returned for both segments: the main segment's via the frame's `current`/`next` fields,
+    /// and the auxiliary segments' via its `aux_current`/`aux_next` fields.
+    pub fn add_trace_polys<B>(
+        &mut self,
+        trace_polys: Option<TracePolyTable<B>>,
+        trace_lde: &[Vec<B>],
+        aux_trace_polys: &[Vec<Vec<E>>],
+        aux_trace_lde: &[Vec<Vec<E>>],
+        domain: &StarkDomain<B>,
+    ) -> EvaluationFrame<E>
     where
         B: StarkField,
         E: From<B>,
     {
-        assert!(self.coefficients.is_empty());
+        assert!(self.coefficients.is_empty() && self.evaluations.is_empty());
 
         // compute a second out-of-domain point offset from z by exactly trace generator; this
         // point defines the "next" computation state in relation to point z
-        let trace_length = trace_polys.poly_size();
+        let trace_length = trace_lde[0].len();
+        let trace_domain_points = trace_domain::<B>(trace_length);
         let g = E::from(B::get_root_of_unity(utils::log2(trace_length)));
         let next_z = self.z * g;
 
-        // compute state of registers at points z and z * g
-        let trace_state1 = trace_polys.evaluate_at(self.z);
-        let trace_state2 = trace_polys.evaluate_at(next_z);
+        // compute state of registers at points z and z * g directly from the trace in
+        // evaluation form, via batched barycentric interpolation; the main and auxiliary
+        // segments share the same trace domain, computed once above
+        let trace_state1 = evaluate_trace_at(trace_lde, &trace_domain_points, self.z);
+        let trace_state2 = evaluate_trace_at(trace_lde, &trace_domain_points, next_z);
+
+        // same, but for the auxiliary (RAP) segments, whose columns are flattened into a single
+        // pair of vectors across all segments
+        let mut aux_state1 = Vec::new();
+        let mut aux_state2 = Vec::new();
+        for segment_lde in aux_trace_lde {
+            aux_state1.extend(evaluate_ext_trace_at(segment_lde, &trace_domain_points, self.z));
+            aux_state2.extend(evaluate_ext_trace_at(segment_lde, &trace_domain_points, next_z));
+        }
+
+        if self.evaluation_domain {
+            self.add_trace_polys_evaluation_form(
+                trace_lde,
+                &trace_domain_points,
+                aux_trace_lde,
+                domain,
+                next_z,
+                &trace_state1,
+                &trace_state2,
+                &aux_state1,
+                &aux_state2,
+            );
+            return EvaluationFrame {
+                current: trace_state1,
+                next: trace_state2,
+                aux_current: aux_state1,
+                aux_next: aux_state2,
+            };
+        }
+
+        // the nontrivial Frobenius conjugates of z: z^(k) = Frobenius^k(z), for k = 1..d-1,
+        // where d is the degree of the extension field over the base field
+        let num_conjugates = self.extension_degree.saturating_sub(1);
+        let z_conjugates: Vec<E> = {
+            let mut conjugates = Vec::with_capacity(num_conjugates);
+            let mut zk = self.z;
+            for _ in 0..num_conjugates {
+                zk = zk.frobenius();
+                conjugates.push(zk);
+            }
+            conjugates
+        };
 
-        // combine trace polynomials into 2 composition polynomials T'(x) and T''(x), and if
-        // we are using a field extension, also T'''(x)
+        // combine trace polynomials into 2 composition polynomials T'(x) and T''(x), and, if we
+        // are using a degree d > 1 extension field, one T^(k)(x) per nontrivial Frobenius
+        // conjugate of z
+        let trace_polys = trace_polys
+            .expect("trace polynomials in coefficient form are required outside of evaluation-domain mode");
         let polys = trace_polys.into_vec();
         let mut t1_composition = E::zeroed_vector(trace_length);
         let mut t2_composition = E::zeroed_vector(trace_length);
-        let mut t3_composition = if self.field_extension {
-            E::zeroed_vector(trace_length)
-        } else {
-            Vec::new()
-        };
+        let mut tk_compositions: Vec<Vec<E>> =
+            (0..num_conjugates).map(|_| E::zeroed_vector(trace_length)).collect();
         for (i, poly) in polys.into_iter().enumerate() {
             // compute T'(x) = T(x) - T(z), multiply it by a pseudo-random coefficient,
             // and add the result into composition polynomial
@@ -115,25 +215,37 @@ impl<E: FieldElement> DeepCompositionPoly<E> {
                 self.cc.trace[i].1,
             );
 
-            // when extension field is enabled, compute T'''(x) = T(x) - T(z_conjugate), multiply
-            // it by a pseudo-random coefficient, and add the result into composition polynomial
-            if self.field_extension {
-                acc_poly(
-                    &mut t3_composition,
-                    &poly,
-                    trace_state1[i].conjugate(),
-                    self.cc.trace[i].2,
-                );
+            // for each nontrivial Frobenius conjugate of z, compute T^(k)(x) = T(x) - T(z^(k)),
+            // multiply it by its own pseudo-random coefficient, and add the result into its
+            // composition polynomial; T(z^(k)) is the k-th Frobenius conjugate of T(z), since
+            // Frobenius commutes with evaluation of a base-field polynomial
+            let mut conjugate_value = trace_state1[i];
+            for (k, t_k) in tk_compositions.iter_mut().enumerate() {
+                conjugate_value = conjugate_value.frobenius();
+                acc_poly(&mut *t_k, &poly, conjugate_value, self.cc.trace[i].2[k]);
+            }
+        }
+
+        // fold in the auxiliary (RAP) segments the same way, except there is no conjugate term:
+        // that term only ever applies to the main, base-field segment
+        let mut aux_col = 0;
+        for segment_polys in aux_trace_polys {
+            for poly in segment_polys {
+                let cc = self.cc.aux_trace[aux_col];
+                acc_poly_ext(&mut t1_composition, poly, aux_state1[aux_col], cc.0);
+                acc_poly_ext(&mut t2_composition, poly, aux_state2[aux_col], cc.1);
+                aux_col += 1;
             }
         }
 
-        // divide the composition polynomials by (x - z), (x - z * g), and (x - z_conjugate)
+        // divide the composition polynomials by (x - z), (x - z * g), and each (x - z^(k))
         // respectively, and add the resulting polynomials together; the output of this step
         // is a single trace polynomial T(x) and deg(T(x)) = trace_length - 2.
-        let trace_poly = merge_trace_compositions(
-            vec![t1_composition, t2_composition, t3_composition],
-            vec![self.z, next_z, self.z.conjugate()],
-        );
+        let mut polys_to_merge = vec![t1_composition, t2_composition];
+        polys_to_merge.extend(tk_compositions);
+        let mut divisors = vec![self.z, next_z];
+        divisors.extend(z_conjugates.iter().copied());
+        let trace_poly = merge_trace_compositions(polys_to_merge, divisors);
 
         // set the coefficients of the DEEP composition polynomial
         self.coefficients = trace_poly;
@@ -143,6 +255,114 @@ impl<E: FieldElement> DeepCompositionPoly<E> {
         EvaluationFrame {
             current: trace_state1,
             next: trace_state2,
+            aux_current: aux_state1,
+            aux_next: aux_state2,
+        }
+    }
+
+    /// Evaluation-domain counterpart of [DeepCompositionPoly::add_trace_polys]. Instead of
+    /// dividing coefficient-form composition polynomials by (x - z) etc. via synthetic
+    /// division, this computes the DEEP quotient pointwise for every x in the LDE domain:
+    ///
+    ///   T'_i(x) = (T_i(x) - T_i(z)) / (x - z)
+    ///
+    /// `trace_lde`/`aux_trace_lde` are only given in evaluation form over the trace domain (see
+    /// [evaluate_trace_at]), not over the full LDE domain, so they are first extended onto the
+    /// LDE domain via the same batched barycentric evaluation used for the OOD points, just
+    /// evaluated at every LDE domain point instead of at z and z * g.
+    ///
+    /// The denominators (x - z), (x - z * g), and (x - z^(k)) for each nontrivial Frobenius
+    /// conjugate z^(k) of z, do not depend on the column, so each is batch-inverted exactly once
+    /// for the whole LDE domain, instead of running one synthetic division per trace column.
+    #[allow(clippy::too_many_arguments)]
+    fn add_trace_polys_evaluation_form<B>(
+        &mut self,
+        trace_lde: &[Vec<B>],
+        trace_domain_points: &[B],
+        aux_trace_lde: &[Vec<Vec<E>>],
+        domain: &StarkDomain<B>,
+        next_z: E,
+        trace_state1: &[E],
+        trace_state2: &[E],
+        aux_state1: &[E],
+        aux_state2: &[E],
+    ) where
+        B: StarkField,
+        E: From<B>,
+    {
+        if self.lde_domain.is_empty() {
+            self.lde_domain = domain.lde_values().iter().map(|&x| E::from(x)).collect();
+        }
+        if self.evaluations.is_empty() {
+            self.evaluations = E::zeroed_vector(self.lde_domain.len());
+        }
+
+        let mut den1: Vec<E> = self.lde_domain.iter().map(|&x| x - self.z).collect();
+        let mut den2: Vec<E> = self.lde_domain.iter().map(|&x| x - next_z).collect();
+        utils::batch_inversion(&mut den1);
+        utils::batch_inversion(&mut den2);
+
+        // nontrivial Frobenius conjugates of z, and their batch-inverted denominators
+        let num_conjugates = self.extension_degree.saturating_sub(1);
+        let mut zk = self.z;
+        let z_conjugates: Vec<E> = (0..num_conjugates)
+            .map(|_| {
+                zk = zk.frobenius();
+                zk
+            })
+            .collect();
+        let den_k: Vec<Vec<E>> = z_conjugates
+            .iter()
+            .map(|&zc| {
+                let mut d: Vec<E> = self.lde_domain.iter().map(|&x| x - zc).collect();
+                utils::batch_inversion(&mut d);
+                d
+            })
+            .collect();
+
+        let trace_over_lde = extend_trace_to_domain(trace_lde, trace_domain_points, &self.lde_domain);
+        for (i, column) in trace_over_lde.iter().enumerate() {
+            let cc = &self.cc.trace[i];
+
+            // T_i(z^(k)) is the k-th Frobenius conjugate of T_i(z), since Frobenius commutes
+            // with evaluation of a base-field polynomial; computed once per column, not per
+            // LDE domain point
+            let mut conjugate_value = trace_state1[i];
+            let conjugate_values: Vec<E> = (0..num_conjugates)
+                .map(|_| {
+                    conjugate_value = conjugate_value.frobenius();
+                    conjugate_value
+                })
+                .collect();
+
+            for k in 0..self.lde_domain.len() {
+                let f_x = column[k];
+                let mut term = (f_x - trace_state1[i]) * den1[k] * cc.0;
+                term += (f_x - trace_state2[i]) * den2[k] * cc.1;
+
+                for (c, den) in den_k.iter().enumerate() {
+                    term += (f_x - conjugate_values[c]) * den[k] * cc.2[c];
+                }
+
+                self.evaluations[k] += term;
+            }
+        }
+
+        // auxiliary (RAP) segments reuse den1/den2, since z and z*g are the same; there is no
+        // conjugate term, as that only applies to the main, base-field segment
+        let mut aux_col = 0;
+        for segment_lde in aux_trace_lde {
+            let segment_over_lde = extend_ext_trace_to_domain(segment_lde, trace_domain_points, &self.lde_domain);
+            for column in segment_over_lde {
+                let cc = self.cc.aux_trace[aux_col];
+                for k in 0..self.lde_domain.len() {
+                    let f_x = column[k];
+                    let term = (f_x - aux_state1[aux_col]) * den1[k] * cc.0
+                        + (f_x - aux_state2[aux_col]) * den2[k] * cc.1;
+                    self.evaluations[k] += term;
+                }
+                aux_col += 1;
+            }
         }
     }
 
@@ -159,13 +379,27 @@ impl<E: FieldElement> DeepCompositionPoly<E> {
     ///   public coin.
     ///
     /// This method returns evaluations of the column polynomials H_i(x) at z^m.
-    pub fn add_composition_poly(&mut self, composition_poly: CompositionPoly<E>) -> Vec<E> {
-        assert!(!self.coefficients.is_empty());
+    ///
+    /// `composition_lde` holds the same composition columns, but already evaluated over the LDE
+    /// domain. When the evaluation-domain prover mode is enabled (see
+    /// [DeepCompositionPoly::new]), dividing out z^m is done pointwise over `composition_lde`
+    /// with a single batch-inverted zerofier, instead of one `syn_div_in_place` per column; see
+    /// [DeepCompositionPoly::add_composition_poly_evaluation_form].
+    pub fn add_composition_poly(
+        &mut self,
+        composition_poly: CompositionPoly<E>,
+        composition_lde: &[Vec<E>],
+    ) -> Vec<E> {
+        assert!(!self.coefficients.is_empty() || !self.evaluations.is_empty());
 
         // compute z^m
         let num_columns = composition_poly.num_columns() as u32;
         let z_m = self.z.exp(num_columns.into());
 
+        if self.evaluation_domain {
+            return self.add_composition_poly_evaluation_form(composition_poly, composition_lde, z_m);
+        }
+
         let mut column_polys = composition_poly.into_columns();
 
         // Divide out the OOD point z from column polynomials
@@ -174,7 +408,7 @@ impl<E: FieldElement> DeepCompositionPoly<E> {
             .iter_mut()
             .map(|poly| {
                 // evaluate the polynomial at point z^m
-                let value_at_z_m = polynom::eval(&poly, z_m);
+                let value_at_z_m = polynom::eval(poly, z_m);
 
                 // compute H'_i(x) = (H_i(x) - H_i(z^m)) / (x - z^m)
                 poly[0] -= value_at_z_m;
@@ -189,7 +423,7 @@ impl<E: FieldElement> DeepCompositionPoly<E> {
             .par_iter_mut()
             .map(|poly| {
                 // evaluate the polynomial at point z'
-                let value_at_z = polynom::eval(&poly, z_m);
+                let value_at_z = polynom::eval(poly, z_m);
 
                 // compute C(x) = (P(x) - P(z)) / (x - z')
                 poly[0] -= value_at_z;
@@ -208,6 +442,41 @@ impl<E: FieldElement> DeepCompositionPoly<E> {
         result
     }
 
+    /// Evaluation-domain counterpart of [DeepCompositionPoly::add_composition_poly]. H_i(z^m) is
+    /// still obtained via a single Horner evaluation per column (cheap, done once); what moves
+    /// to the evaluation domain is dividing out (x - z^m), which is identical for every column
+    /// and is therefore batch-inverted once for the whole LDE domain.
+    fn add_composition_poly_evaluation_form(
+        &mut self,
+        composition_poly: CompositionPoly<E>,
+        composition_lde: &[Vec<E>],
+        z_m: E,
+    ) -> Vec<E> {
+        assert!(!self.lde_domain.is_empty());
+
+        let column_polys = composition_poly.into_columns();
+
+        #[cfg(not(feature = "concurrent"))]
+        let result: Vec<E> = column_polys.iter().map(|poly| polynom::eval(poly, z_m)).collect();
+        #[cfg(feature = "concurrent")]
+        let result: Vec<E> = column_polys
+            .par_iter()
+            .map(|poly| polynom::eval(poly, z_m))
+            .collect();
+
+        let mut den: Vec<E> = self.lde_domain.iter().map(|&x| x - z_m).collect();
+        utils::batch_inversion(&mut den);
+
+        for (i, column) in composition_lde.iter().enumerate() {
+            let cc = self.cc.constraints[i];
+            for k in 0..self.lde_domain.len() {
+                self.evaluations[k] += (column[k] - result[i]) * den[k] * cc;
+            }
+        }
+
+        result
+    }
+
     // FINAL DEGREE ADJUSTMENT
     // --------------------------------------------------------------------------------------------
     /// Increase the degree of the DEEP composition polynomial by one. After add_trace_polys() and
@@ -217,7 +486,18 @@ impl<E: FieldElement> DeepCompositionPoly<E> {
     /// ensure that degree of the DEEP composition polynomial is trace_length - 1, so we make the
     /// adjustment here by computing C'(x) = C(x) * (cc_0 + x * cc_1), where cc_0 and cc_1 are the
     /// coefficients for the random linear combination drawn from the public coin.
+    ///
+    /// In the evaluation-domain mode, this adjustment is applied pointwise over the LDE domain
+    /// instead: `evaluations[k] *= cc_0 + lde_domain[k] * cc_1`.
     pub fn adjust_degree(&mut self) {
+        if self.evaluation_domain {
+            for k in 0..self.evaluations.len() {
+                let scale = self.cc.degree.0 + self.lde_domain[k] * self.cc.degree.1;
+                self.evaluations[k] *= scale;
+            }
+            return;
+        }
+
         assert_eq!(self.poly_size() - 2, self.degree());
 
         let mut result = E::zeroed_vector(self.coefficients.len());
@@ -238,11 +518,19 @@ impl<E: FieldElement> DeepCompositionPoly<E> {
     // LOW-DEGREE EXTENSION
     // --------------------------------------------------------------------------------------------
     /// Evaluates DEEP composition polynomial over the specified LDE domain and returns the result.
+    ///
+    /// In the evaluation-domain mode, the polynomial was already accumulated in evaluation form
+    /// over `domain` by [DeepCompositionPoly::add_trace_polys] and
+    /// [DeepCompositionPoly::add_composition_poly], so this is a no-op lookup rather than an FFT.
     pub fn evaluate<B>(self, domain: &StarkDomain<B>) -> Vec<E>
     where
         B: StarkField,
         E: From<B>,
     {
+        if self.evaluation_domain {
+            return self.evaluations;
+        }
+
         fft::evaluate_poly_with_offset(
             &self.coefficients,
             domain.trace_twiddles(),
@@ -261,8 +549,7 @@ impl<E: FieldElement> DeepCompositionPoly<E> {
 fn merge_trace_compositions<E: FieldElement>(mut polys: Vec<Vec<E>>, divisors: Vec<E>) -> Vec<E> {
     // divide all polynomials by their corresponding divisor
     for (poly, &divisor) in polys.iter_mut().zip(divisors.iter()) {
-        // skip empty polynomials; this could happen for conjugate composition polynomial (T3)
-        // when extension field is not enabled.
+        // skip empty polynomials, in case any were passed in
         if !poly.is_empty() {
             polynom::syn_div_in_place(poly, 1, divisor);
         }
@@ -301,8 +588,153 @@ fn merge_trace_compositions<E: FieldElement>(mut polys: Vec<Vec<E>>, divisors: V
     result
 }
 
+/// Evaluates all columns of `trace` (given in evaluation form over the trace domain, i.e.
+/// `trace[i][j] = T_i(\omega^j)` for the n-th roots of unity `\omega^j`) at the out-of-domain
+/// point `z`, using the barycentric formula:
+///
+///   T_i(z) = ((z^n - 1) / n) * sum_j( T_i(\omega^j) * \omega^j / (z - \omega^j) )
+///
+/// `domain` must be the n-th roots of unity for the trace domain (see [trace_domain]); it is
+/// taken as a parameter, rather than recomputed from `trace[0].len()`, so that callers evaluating
+/// both the main and auxiliary trace segments at the same point can share a single domain and
+/// are not left relying on the two segments' lengths happening to agree.
+///
+/// All columns share the same denominators `(z - \omega^j)`, so they are computed and inverted
+/// once via a single batch inversion, and then reused for every column. Since `z` is sampled
+/// outside of the trace domain, none of the denominators are zero, and no special-casing is
+/// required.
+fn evaluate_trace_at<B, E>(trace: &[Vec<B>], domain: &[B], z: E) -> Vec<E>
+where
+    B: StarkField,
+    E: FieldElement + From<B>,
+{
+    let (denominators, scale) = barycentric_weights(domain, z);
+
+    #[cfg(not(feature = "concurrent"))]
+    let result = trace.iter();
+    #[cfg(feature = "concurrent")]
+    let result = trace.par_iter();
+
+    result
+        .map(|column| {
+            let sum = column.iter().zip(domain.iter()).zip(denominators.iter()).fold(
+                E::ZERO,
+                |acc, ((&f_j, &x_j), &d_inv)| acc + E::from(f_j) * E::from(x_j) * d_inv,
+            );
+            sum * scale
+        })
+        .collect()
+}
+
+/// Same as [evaluate_trace_at], but for auxiliary (RAP) trace segments, whose columns are
+/// already defined over the extension field `E` rather than the base field `B`; `domain` gives
+/// the n-th roots of unity for the trace domain (shared with the main segment).
+fn evaluate_ext_trace_at<B, E>(trace: &[Vec<E>], domain: &[B], z: E) -> Vec<E>
+where
+    B: StarkField,
+    E: FieldElement + From<B>,
+{
+    let (denominators, scale) = barycentric_weights(domain, z);
+
+    #[cfg(not(feature = "concurrent"))]
+    let result = trace.iter();
+    #[cfg(feature = "concurrent")]
+    let result = trace.par_iter();
+
+    result
+        .map(|column| {
+            debug_assert_eq!(
+                column.len(),
+                domain.len(),
+                "auxiliary trace column length must match the trace domain, or the zip below \
+                 silently truncates to the shorter of the two"
+            );
+            let sum = column.iter().zip(domain.iter()).zip(denominators.iter()).fold(
+                E::ZERO,
+                |acc, ((&f_j, &x_j), &d_inv)| acc + f_j * E::from(x_j) * d_inv,
+            );
+            sum * scale
+        })
+        .collect()
+}
+
+/// Extends `trace` (given in evaluation form over `trace_domain`, see [evaluate_trace_at]) onto
+/// `target_domain`, by evaluating each column at every point of `target_domain` via the same
+/// batched barycentric formula used for OOD evaluation. Returns one vector per column, indexed
+/// the same way as `target_domain`.
+fn extend_trace_to_domain<B, E>(trace: &[Vec<B>], trace_domain: &[B], target_domain: &[E]) -> Vec<Vec<E>>
+where
+    B: StarkField,
+    E: FieldElement + From<B>,
+{
+    let mut columns = vec![Vec::with_capacity(target_domain.len()); trace.len()];
+    for &x in target_domain {
+        let values = evaluate_trace_at(trace, trace_domain, x);
+        for (column, value) in columns.iter_mut().zip(values) {
+            column.push(value);
+        }
+    }
+    columns
+}
+
+/// Same as [extend_trace_to_domain], but for auxiliary (RAP) trace segments (see
+/// [evaluate_ext_trace_at]).
+fn extend_ext_trace_to_domain<B, E>(trace: &[Vec<E>], trace_domain: &[B], target_domain: &[E]) -> Vec<Vec<E>>
+where
+    B: StarkField,
+    E: FieldElement + From<B>,
+{
+    let mut columns = vec![Vec::with_capacity(target_domain.len()); trace.len()];
+    for &x in target_domain {
+        let values = evaluate_ext_trace_at(trace, trace_domain, x);
+        for (column, value) in columns.iter_mut().zip(values) {
+            column.push(value);
+        }
+    }
+    columns
+}
+
+/// Returns the n-th roots of unity {omega^0, ..., omega^{n-1}} that make up the trace domain.
+fn trace_domain<B: StarkField>(trace_length: usize) -> Vec<B> {
+    let g = B::get_root_of_unity(utils::log2(trace_length));
+    let mut domain = Vec::with_capacity(trace_length);
+    let mut x = B::ONE;
+    for _ in 0..trace_length {
+        domain.push(x);
+        x *= g;
+    }
+    domain
+}
+
+/// Computes the batch-inverted barycentric denominators `(z - omega^j)^{-1}` and the shared
+/// scaling factor `(z^n - 1) / n` for the out-of-domain point `z`, relative to the trace domain
+/// `domain`. Since `z` is sampled outside of the trace domain, none of the denominators are
+/// zero, and no special-casing is required.
+fn barycentric_weights<B, E>(domain: &[B], z: E) -> (Vec<E>, E)
+where
+    B: StarkField,
+    E: FieldElement + From<B>,
+{
+    let trace_length = domain.len();
+    let log_trace_length = utils::log2(trace_length);
+
+    let mut denominators: Vec<E> = domain.iter().map(|&x| z - E::from(x)).collect();
+    utils::batch_inversion(&mut denominators);
+
+    // shared scaling factor (z^n - 1) / n; n is a power of two, so it is computed via repeated
+    // doubling rather than requiring a conversion from usize into a field element
+    let z_n = z.exp((trace_length as u32).into());
+    let mut n_field = E::ONE;
+    for _ in 0..log_trace_length {
+        n_field = n_field + n_field;
+    }
+    let scale = (z_n - E::ONE) * n_field.inv();
+
+    (denominators, scale)
+}
+
 /// Computes (P(x) - value) * k and saves the result into the accumulator
-fn acc_poly<B, E>(accumulator: &mut Vec<E>, poly: &[B], value: E, k: E)
+fn acc_poly<B, E>(accumulator: &mut [E], poly: &[B], value: E, k: E)
 where
     B: StarkField,
     E: FieldElement + From<B>,
@@ -310,4 +742,235 @@ where
     utils::mul_acc(accumulator, poly, k);
     let adjusted_tz = value * k;
     accumulator[0] -= adjusted_tz;
+}
+
+/// Same as [acc_poly], but for a polynomial that is already defined over `E` (e.g. an
+/// auxiliary RAP trace column), rather than over the base field `B`.
+fn acc_poly_ext<E: FieldElement>(accumulator: &mut [E], poly: &[E], value: E, k: E) {
+    utils::mul_acc(accumulator, poly, k);
+    accumulator[0] -= value * k;
+}
+
+// TESTS
+// ================================================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{FieldExtension, ProverOptions};
+    use math::field::toy::BaseElement;
+
+    /// `evaluate_trace_at` (the batched barycentric OOD evaluation) must agree with evaluating
+    /// the same polynomial directly via Horner's method, for a point outside the trace domain.
+    #[test]
+    fn evaluate_trace_at_matches_direct_evaluation() {
+        let poly = vec![
+            BaseElement::new(3),
+            BaseElement::new(5),
+            BaseElement::new(7),
+            BaseElement::new(2),
+        ];
+        let trace_length = poly.len();
+        let domain = trace_domain::<BaseElement>(trace_length);
+
+        let column: Vec<BaseElement> = domain.iter().map(|&x| polynom::eval(&poly, x)).collect();
+        let trace = vec![column];
+
+        let z = BaseElement::new(50);
+        assert!(!domain.contains(&z), "z must lie outside the trace domain");
+
+        let expected = polynom::eval(&poly, z);
+        let actual = evaluate_trace_at(&trace, &domain, z);
+
+        assert_eq!(actual, vec![expected]);
+    }
+
+    /// The evaluation-domain DEEP composition pipeline must produce the same evaluations as the
+    /// coefficient-form pipeline, for the same trace, point z, and composition coefficients.
+    #[test]
+    fn evaluation_domain_matches_coefficient_form() {
+        let poly = vec![
+            BaseElement::new(3),
+            BaseElement::new(5),
+            BaseElement::new(7),
+            BaseElement::new(2),
+        ];
+        let trace_length = poly.len();
+        let trace_domain_points = trace_domain::<BaseElement>(trace_length);
+        let column: Vec<BaseElement> =
+            trace_domain_points.iter().map(|&x| polynom::eval(&poly, x)).collect();
+        let trace_lde = vec![column];
+
+        let z = BaseElement::new(10);
+        let domain = StarkDomain::new(trace_length, 2, BaseElement::new(3));
+
+        let cc = CompositionCoefficients {
+            trace: vec![(BaseElement::new(11), BaseElement::new(13), vec![])],
+            aux_trace: vec![],
+            constraints: vec![],
+            degree: (BaseElement::new(17), BaseElement::new(19)),
+        };
+
+        let coeff_ctx =
+            ComputationContext::new(ProverOptions::new(FieldExtension::None, false));
+        let mut coeff_composer = DeepCompositionPoly::new(&coeff_ctx, z, cc.clone());
+        coeff_composer.add_trace_polys(
+            Some(TracePolyTable::new(vec![poly.clone()])),
+            &trace_lde,
+            &[],
+            &[],
+            &domain,
+        );
+        coeff_composer.adjust_degree();
+        let coeff_result = coeff_composer.evaluate(&domain);
+
+        let eval_ctx = ComputationContext::new(ProverOptions::new(FieldExtension::None, true));
+        let mut eval_composer = DeepCompositionPoly::new(&eval_ctx, z, cc);
+        eval_composer.add_trace_polys(None, &trace_lde, &[], &[], &domain);
+        eval_composer.adjust_degree();
+        let eval_result = eval_composer.evaluate(&domain);
+
+        assert_eq!(coeff_result, eval_result);
+    }
+
+    /// Auxiliary (RAP) trace columns, across multiple segments, must each be composed with the
+    /// `cc.aux_trace` entry at their own flattened index (segment 0's columns first, then
+    /// segment 1's, etc.), not e.g. every segment reusing the first entry or segments landing
+    /// in the wrong order.
+    #[test]
+    fn aux_trace_columns_use_their_own_composition_coefficients() {
+        let main_poly = vec![
+            BaseElement::new(3),
+            BaseElement::new(5),
+            BaseElement::new(7),
+            BaseElement::new(2),
+        ];
+        let trace_length = main_poly.len();
+        let trace_domain_points = trace_domain::<BaseElement>(trace_length);
+        let eval_over_trace_domain = |poly: &[BaseElement]| -> Vec<BaseElement> {
+            trace_domain_points.iter().map(|&x| polynom::eval(poly, x)).collect()
+        };
+
+        let main_lde = vec![eval_over_trace_domain(&main_poly)];
+
+        // two auxiliary segments: segment 0 has two columns, segment 1 has one
+        let aux_polys = vec![
+            vec![
+                vec![BaseElement::new(1), BaseElement::new(2), BaseElement::new(3), BaseElement::new(4)],
+                vec![BaseElement::new(5), BaseElement::new(6), BaseElement::new(7), BaseElement::new(8)],
+            ],
+            vec![vec![BaseElement::new(9), BaseElement::new(10), BaseElement::new(11), BaseElement::new(12)]],
+        ];
+        let aux_lde: Vec<Vec<Vec<BaseElement>>> = aux_polys
+            .iter()
+            .map(|segment| segment.iter().map(|poly| eval_over_trace_domain(poly)).collect())
+            .collect();
+
+        let z = BaseElement::new(10);
+        let domain = StarkDomain::new(trace_length, 1, BaseElement::new(3));
+        let g = BaseElement::get_root_of_unity(utils::log2(trace_length));
+        let next_z = z * g;
+
+        let cc = CompositionCoefficients {
+            trace: vec![(BaseElement::new(11), BaseElement::new(13), vec![])],
+            aux_trace: vec![
+                (BaseElement::new(21), BaseElement::new(23)),
+                (BaseElement::new(27), BaseElement::new(29)),
+                (BaseElement::new(31), BaseElement::new(37)),
+            ],
+            constraints: vec![],
+            degree: (BaseElement::new(17), BaseElement::new(19)),
+        };
+
+        let ctx = ComputationContext::new(ProverOptions::new(FieldExtension::None, false));
+        let mut composer = DeepCompositionPoly::new(&ctx, z, cc.clone());
+        composer.add_trace_polys(
+            Some(TracePolyTable::new(vec![main_poly.clone()])),
+            &main_lde,
+            &aux_polys,
+            &aux_lde,
+            &domain,
+        );
+
+        // manually fold every column (main, then aux columns in their flattened order) using the
+        // same low-level primitives, driving the aux_trace indexing explicitly here rather than
+        // relying on add_trace_polys's own bookkeeping
+        let mut t1 = BaseElement::zeroed_vector(trace_length);
+        let mut t2 = BaseElement::zeroed_vector(trace_length);
+        acc_poly(&mut t1, &main_poly, polynom::eval(&main_poly, z), cc.trace[0].0);
+        acc_poly(&mut t2, &main_poly, polynom::eval(&main_poly, next_z), cc.trace[0].1);
+
+        let flattened_aux_polys: Vec<&Vec<BaseElement>> =
+            aux_polys.iter().flat_map(|segment| segment.iter()).collect();
+        for (aux_col, poly) in flattened_aux_polys.into_iter().enumerate() {
+            let aux_cc = cc.aux_trace[aux_col];
+            acc_poly_ext(&mut t1, poly, polynom::eval(poly, z), aux_cc.0);
+            acc_poly_ext(&mut t2, poly, polynom::eval(poly, next_z), aux_cc.1);
+        }
+        let expected_coefficients = merge_trace_compositions(vec![t1, t2], vec![z, next_z]);
+
+        assert_eq!(composer.coefficients, expected_coefficients);
+    }
+
+    /// When proving over a degree-2 extension field, the Frobenius conjugate term T^(1)(x) must
+    /// use `z`'s own Frobenius conjugate (not `z` itself, and not some other point), with
+    /// `T_i(z^(1))` computed as the conjugate of `T_i(z)` (Frobenius commutes with evaluation of
+    /// a base-field polynomial), each folded in with its own `cc.trace[i].2[0]` coefficient.
+    #[test]
+    fn conjugate_term_uses_frobenius_conjugate_of_z() {
+        use math::field::toy::QuadElement;
+
+        let main_poly = vec![
+            BaseElement::new(3),
+            BaseElement::new(5),
+            BaseElement::new(7),
+            BaseElement::new(2),
+        ];
+        let trace_length = main_poly.len();
+        let trace_domain_points = trace_domain::<BaseElement>(trace_length);
+        let column: Vec<BaseElement> =
+            trace_domain_points.iter().map(|&x| polynom::eval(&main_poly, x)).collect();
+        let trace_lde = vec![column];
+
+        // a point that does not lie in the base field, so its Frobenius conjugate is distinct
+        // from itself
+        let z = QuadElement::new(BaseElement::new(10), BaseElement::new(1));
+        let domain = StarkDomain::new(trace_length, 1, BaseElement::new(3));
+
+        let cc = CompositionCoefficients {
+            trace: vec![(
+                QuadElement::new(BaseElement::new(11), BaseElement::new(2)),
+                QuadElement::new(BaseElement::new(13), BaseElement::new(4)),
+                vec![QuadElement::new(BaseElement::new(15), BaseElement::new(6))],
+            )],
+            aux_trace: vec![],
+            constraints: vec![],
+            degree: (QuadElement::new(BaseElement::new(17), BaseElement::new(8)), QuadElement::ONE),
+        };
+
+        let ctx = ComputationContext::new(ProverOptions::new(FieldExtension::Quadratic, false));
+        let mut composer = DeepCompositionPoly::new(&ctx, z, cc.clone());
+        composer.add_trace_polys(
+            Some(TracePolyTable::new(vec![main_poly.clone()])),
+            &trace_lde,
+            &[],
+            &[],
+            &domain,
+        );
+
+        let g = QuadElement::from(BaseElement::get_root_of_unity(utils::log2(trace_length)));
+        let next_z = z * g;
+        let z_conjugate = z.frobenius();
+
+        let poly: Vec<QuadElement> = main_poly.iter().map(|&c| QuadElement::from(c)).collect();
+        let mut t1 = QuadElement::zeroed_vector(trace_length);
+        let mut t2 = QuadElement::zeroed_vector(trace_length);
+        let mut tk = QuadElement::zeroed_vector(trace_length);
+        acc_poly_ext(&mut t1, &poly, polynom::eval(&poly, z), cc.trace[0].0);
+        acc_poly_ext(&mut t2, &poly, polynom::eval(&poly, next_z), cc.trace[0].1);
+        acc_poly_ext(&mut tk, &poly, polynom::eval(&poly, z_conjugate), cc.trace[0].2[0]);
+        let expected_coefficients =
+            merge_trace_compositions(vec![t1, t2, tk], vec![z, next_z, z_conjugate]);
+
+        assert_eq!(composer.coefficients, expected_coefficients);
+    }
 }
\ No newline at end of file
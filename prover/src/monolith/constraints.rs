@@ -0,0 +1,28 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+// COMPOSITION POLYNOMIAL
+// ================================================================================================
+/// Holds the column polynomials making up the constraint composition polynomial.
+pub struct CompositionPoly<E> {
+    columns: Vec<Vec<E>>,
+}
+
+impl<E> CompositionPoly<E> {
+    /// Creates a new [CompositionPoly] from the given column polynomials.
+    pub fn new(columns: Vec<Vec<E>>) -> Self {
+        CompositionPoly { columns }
+    }
+
+    /// Returns the number of columns making up this composition polynomial.
+    pub fn num_columns(&self) -> usize {
+        self.columns.len()
+    }
+
+    /// Consumes this composition polynomial and returns its column polynomials.
+    pub fn into_columns(self) -> Vec<Vec<E>> {
+        self.columns
+    }
+}
@@ -0,0 +1,45 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+mod composer;
+mod constraints;
+
+use common::{ComputationContext, CompositionCoefficients, EvaluationFrame};
+use composer::DeepCompositionPoly;
+use math::field::{FieldElement, StarkField};
+
+use super::{StarkDomain, TracePolyTable};
+
+// DEEP COMPOSITION
+// ================================================================================================
+/// Builds the DEEP composition polynomial for the trace portion of the proof and returns it
+/// together with the out-of-domain evaluation frame that gets folded into the proof.
+///
+/// When `context.options().use_evaluation_domain_composition()` is set, `trace_polys` is not
+/// interpolated by the caller at all (passed as `None`): the DEEP quotient is instead computed
+/// directly from `trace_lde`, entirely in evaluation form.
+///
+/// `aux_trace_polys`/`aux_trace_lde` carry the auxiliary (RAP) trace segments, if any; pass empty
+/// slices when the computation has no auxiliary segments.
+#[allow(clippy::too_many_arguments)]
+pub fn build_trace_deep_composition_poly<B, E>(
+    context: &ComputationContext,
+    z: E,
+    cc: CompositionCoefficients<E>,
+    trace_polys: Option<TracePolyTable<B>>,
+    trace_lde: &[Vec<B>],
+    aux_trace_polys: &[Vec<Vec<E>>],
+    aux_trace_lde: &[Vec<Vec<E>>],
+    domain: &StarkDomain<B>,
+) -> (DeepCompositionPoly<E>, EvaluationFrame<E>)
+where
+    B: StarkField,
+    E: FieldElement + From<B>,
+{
+    let mut composer = DeepCompositionPoly::new(context, z, cc);
+    let frame =
+        composer.add_trace_polys(trace_polys, trace_lde, aux_trace_polys, aux_trace_lde, domain);
+    (composer, frame)
+}
@@ -0,0 +1,23 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+// TRACE POLYNOMIAL TABLE
+// ================================================================================================
+/// Holds the coefficient-form polynomials for each column of the execution trace.
+pub struct TracePolyTable<B> {
+    polys: Vec<Vec<B>>,
+}
+
+impl<B> TracePolyTable<B> {
+    /// Creates a new [TracePolyTable] from the given column polynomials.
+    pub fn new(polys: Vec<Vec<B>>) -> Self {
+        TracePolyTable { polys }
+    }
+
+    /// Consumes this table and returns its column polynomials.
+    pub fn into_vec(self) -> Vec<Vec<B>> {
+        self.polys
+    }
+}